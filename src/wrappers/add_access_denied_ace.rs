@@ -0,0 +1,26 @@
+use crate::{Acl, Sid};
+use std::io;
+use winapi::um::winnt::ACL_REVISION;
+
+/// Wraps AddAccessDeniedAce
+///
+/// Appends an access-denied ACE denying `mask` to `trustee` onto the end
+/// of `acl`. `acl` must have enough free space for the new ACE; see
+/// [`Acl::builder`](super::super::Acl::builder).
+#[allow(non_snake_case)]
+pub fn AddAccessDeniedAce(acl: &mut Acl, mask: u32, trustee: &Sid) -> io::Result<()> {
+    let result = unsafe {
+        winapi::um::securitybaseapi::AddAccessDeniedAce(
+            acl.as_ptr() as *mut _,
+            ACL_REVISION,
+            mask,
+            trustee.as_ptr() as *mut _,
+        )
+    };
+
+    if result == 0 {
+        return Err(io::Error::last_os_error());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use crate::SecurityDescriptor;
+use std::ffi::OsString;
+use std::io;
+use std::os::windows::ffi::OsStringExt;
+use std::ptr;
+use winapi::shared::minwindef::DWORD;
+use winapi::um::winbase::{ConvertSecurityDescriptorToStringSecurityDescriptorW, LocalFree};
+use winapi::um::winnt::{
+    DACL_SECURITY_INFORMATION, GROUP_SECURITY_INFORMATION, OWNER_SECURITY_INFORMATION,
+    SDDL_REVISION_1,
+};
+
+/// Wraps ConvertSecurityDescriptorToStringSecurityDescriptorW
+///
+/// Renders the owner, group, and DACL; the SACL is left out since reading
+/// it usually requires `SE_SECURITY_NAME` privilege the caller may not
+/// hold.
+#[allow(non_snake_case)]
+pub fn ConvertSecurityDescriptorToStringSecurityDescriptor(
+    sd: &SecurityDescriptor,
+) -> io::Result<OsString> {
+    let info: DWORD =
+        OWNER_SECURITY_INFORMATION | GROUP_SECURITY_INFORMATION | DACL_SECURITY_INFORMATION;
+
+    let mut buf: *mut u16 = ptr::null_mut();
+    let mut len: DWORD = 0;
+
+    let result = unsafe {
+        ConvertSecurityDescriptorToStringSecurityDescriptorW(
+            sd.as_ptr() as *mut _,
+            SDDL_REVISION_1 as u32,
+            info,
+            &mut buf,
+            &mut len,
+        )
+    };
+
+    if result == 0 {
+        return Err(io::Error::last_os_error());
+    }
+
+    // `len` includes the nul terminator; OsString doesn't want it.
+    let slice = unsafe { std::slice::from_raw_parts(buf, len.saturating_sub(1) as usize) };
+    let string = OsString::from_wide(slice);
+
+    unsafe { LocalFree(buf as *mut _) };
+
+    Ok(string)
+}
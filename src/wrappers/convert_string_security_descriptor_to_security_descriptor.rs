@@ -0,0 +1,41 @@
+use crate::{wrappers, SecurityDescriptor};
+use std::ffi::OsStr;
+use std::io;
+use std::os::windows::ffi::OsStrExt;
+use std::ptr;
+use winapi::um::winbase::ConvertStringSecurityDescriptorToSecurityDescriptorW;
+use winapi::um::winnt::{PSECURITY_DESCRIPTOR, SDDL_REVISION_1};
+
+/// Wraps ConvertStringSecurityDescriptorToSecurityDescriptorW
+///
+/// The descriptor this returns is a single self-relative blob allocated
+/// with `LocalAlloc`, with the owner/group/DACL/SACL pointers pulled back
+/// out with `GetSecurityDescriptorOwner`/`...Group`/`...Dacl`/`...Sacl` so
+/// it can be wrapped with the existing `SecurityDescriptor::from_raw`.
+#[allow(non_snake_case)]
+pub fn ConvertStringSecurityDescriptorToSecurityDescriptor(
+    sddl: &OsStr,
+) -> io::Result<SecurityDescriptor> {
+    let wide: Vec<u16> = sddl.encode_wide().chain(Some(0)).collect();
+    let mut sd: PSECURITY_DESCRIPTOR = ptr::null_mut();
+
+    let result = unsafe {
+        ConvertStringSecurityDescriptorToSecurityDescriptorW(
+            wide.as_ptr(),
+            SDDL_REVISION_1 as u32,
+            &mut sd,
+            ptr::null_mut(),
+        )
+    };
+
+    if result == 0 {
+        return Err(io::Error::last_os_error());
+    }
+
+    let owner = wrappers::GetSecurityDescriptorOwner(sd);
+    let group = wrappers::GetSecurityDescriptorGroup(sd);
+    let dacl = wrappers::GetSecurityDescriptorDacl(sd);
+    let sacl = wrappers::GetSecurityDescriptorSacl(sd);
+
+    Ok(unsafe { SecurityDescriptor::from_raw(sd, owner, group, dacl, sacl) })
+}
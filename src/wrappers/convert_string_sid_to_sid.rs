@@ -0,0 +1,28 @@
+use crate::Sid;
+use std::ffi::OsStr;
+use std::io;
+use std::os::windows::ffi::OsStrExt;
+use std::ptr;
+use std::ptr::NonNull;
+use winapi::um::winbase::ConvertStringSidToSidW;
+
+/// Wraps ConvertStringSidToSidW
+///
+/// The SID returned by `ConvertStringSidToSidW` is allocated with
+/// `LocalAlloc`, so it's wrapped with `Sid::owned_from_nonnull` and drops
+/// cleanly under the existing `LocalFree`-based `Drop` impl.
+#[allow(non_snake_case)]
+pub fn ConvertStringSidToSid(s: &OsStr) -> io::Result<Sid> {
+    let wide: Vec<u16> = s.encode_wide().chain(Some(0)).collect();
+    let mut psid = ptr::null_mut();
+
+    let result = unsafe { ConvertStringSidToSidW(wide.as_ptr(), &mut psid) };
+
+    if result == 0 {
+        return Err(io::Error::last_os_error());
+    }
+
+    let ptr = NonNull::new(psid)
+        .expect("ConvertStringSidToSidW reported success but returned a null pointer");
+    Ok(unsafe { Sid::owned_from_nonnull(ptr) })
+}
@@ -0,0 +1,53 @@
+use crate::{Sid, WellKnownSidType};
+use std::io;
+use std::ptr;
+use std::ptr::NonNull;
+use winapi::shared::minwindef::DWORD;
+use winapi::shared::winerror::ERROR_INSUFFICIENT_BUFFER;
+use winapi::um::securitybaseapi::CreateWellKnownSid as CreateWellKnownSid_sys;
+use winapi::um::winbase::{LocalAlloc, LocalFree, LPTR};
+
+/// Wraps CreateWellKnownSid
+///
+/// `domain_sid` is only meaningful for well-known SIDs that are relative to
+/// a domain; pass `None` to let the system use the local computer's domain
+/// (or no domain at all) as appropriate for `sid_type`.
+///
+/// This follows the usual two-call pattern: the first call is made with a
+/// null buffer to discover the required length, then a buffer of that
+/// length is allocated with `LocalAlloc` (so that the returned `Sid`'s
+/// `LocalFree`-based `Drop` impl stays valid) and the call is repeated to
+/// fill it in.
+#[allow(non_snake_case)]
+pub fn CreateWellKnownSid(
+    sid_type: WellKnownSidType,
+    domain_sid: Option<&Sid>,
+) -> io::Result<Sid> {
+    let domain_ptr = domain_sid
+        .map(|sid| sid.as_ptr() as *mut _)
+        .unwrap_or(ptr::null_mut());
+
+    let mut len: DWORD = 0;
+    let success =
+        unsafe { CreateWellKnownSid_sys(sid_type, domain_ptr, ptr::null_mut(), &mut len) };
+
+    if success == 0 {
+        let err = io::Error::last_os_error();
+        if err.raw_os_error() != Some(ERROR_INSUFFICIENT_BUFFER as i32) {
+            return Err(err);
+        }
+    }
+
+    let buf = unsafe { LocalAlloc(LPTR, len as usize) };
+    let buf = NonNull::new(buf).ok_or_else(io::Error::last_os_error)?;
+
+    let success = unsafe { CreateWellKnownSid_sys(sid_type, domain_ptr, buf.as_ptr(), &mut len) };
+
+    if success == 0 {
+        let err = io::Error::last_os_error();
+        unsafe { LocalFree(buf.as_ptr()) };
+        return Err(err);
+    }
+
+    Ok(unsafe { Sid::owned_from_nonnull(buf) })
+}
@@ -0,0 +1,27 @@
+use crate::Acl;
+use std::io;
+use std::ptr;
+use winapi::ctypes::c_void;
+
+/// Wraps GetAce
+///
+/// Returns a pointer to the `index`th ACE header in `acl`. Returns `None`
+/// if `index` is out of bounds rather than surfacing the underlying
+/// `ERROR_INVALID_PARAMETER`.
+#[allow(non_snake_case)]
+pub fn GetAce(acl: &Acl, index: u32) -> io::Result<Option<*const c_void>> {
+    let mut ace: *mut c_void = ptr::null_mut();
+
+    let result =
+        unsafe { winapi::um::securitybaseapi::GetAce(acl.as_ptr() as *mut _, index, &mut ace) };
+
+    if result == 0 {
+        let err = io::Error::last_os_error();
+        if err.raw_os_error() == Some(winapi::shared::winerror::ERROR_INVALID_PARAMETER as i32) {
+            return Ok(None);
+        }
+        return Err(err);
+    }
+
+    Ok(Some(ace))
+}
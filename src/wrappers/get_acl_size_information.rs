@@ -0,0 +1,25 @@
+use crate::Acl;
+use std::io;
+use std::mem;
+use winapi::um::winnt::{ACL_SIZE_INFORMATION, AclSizeInformation};
+
+/// Wraps GetAclInformation, requesting an `AclSizeInformation` class result
+#[allow(non_snake_case)]
+pub fn GetAclSizeInformation(acl: &Acl) -> io::Result<ACL_SIZE_INFORMATION> {
+    let mut info: ACL_SIZE_INFORMATION = unsafe { mem::zeroed() };
+
+    let result = unsafe {
+        winapi::um::securitybaseapi::GetAclInformation(
+            acl.as_ptr() as *mut _,
+            &mut info as *mut _ as *mut _,
+            mem::size_of::<ACL_SIZE_INFORMATION>() as u32,
+            AclSizeInformation,
+        )
+    };
+
+    if result == 0 {
+        return Err(io::Error::last_os_error());
+    }
+
+    Ok(info)
+}
@@ -0,0 +1,31 @@
+use crate::{Acl, Sid};
+use std::io;
+use std::mem;
+use winapi::shared::winerror::ERROR_SUCCESS;
+use winapi::um::accctrl::{TRUSTEE_IS_SID, TRUSTEE_IS_UNKNOWN, TRUSTEE_W};
+use winapi::um::winnt::ACCESS_MASK;
+
+/// Wraps GetEffectiveRightsFromAclW
+///
+/// Builds a `TRUSTEE_W` with `TrusteeForm` set to `TRUSTEE_IS_SID` and
+/// `ptstrName` pointed at `trustee`'s SID, as the API requires when asking
+/// about a SID rather than a name.
+#[allow(non_snake_case)]
+pub fn GetEffectiveRightsFromAcl(acl: &Acl, trustee: &Sid) -> io::Result<ACCESS_MASK> {
+    let mut trustee_w: TRUSTEE_W = unsafe { mem::zeroed() };
+    trustee_w.TrusteeForm = TRUSTEE_IS_SID;
+    trustee_w.TrusteeType = TRUSTEE_IS_UNKNOWN;
+    trustee_w.ptstrName = trustee.as_ptr() as *mut _;
+
+    let mut mask: ACCESS_MASK = 0;
+
+    let result = unsafe {
+        winapi::um::aclapi::GetEffectiveRightsFromAclW(acl.as_ptr() as *mut _, &trustee_w, &mut mask)
+    };
+
+    if result != ERROR_SUCCESS {
+        return Err(io::Error::from_raw_os_error(result as i32));
+    }
+
+    Ok(mask)
+}
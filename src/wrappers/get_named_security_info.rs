@@ -0,0 +1,49 @@
+use crate::{SecurityDescriptor, SecurityInformation};
+use std::ffi::OsStr;
+use std::io;
+use std::os::windows::ffi::OsStrExt;
+use std::ptr;
+use winapi::shared::winerror::ERROR_SUCCESS;
+use winapi::um::accctrl::SE_FILE_OBJECT;
+use winapi::um::aclapi::GetNamedSecurityInfoW;
+use winapi::um::winnt::{PACL, PSECURITY_DESCRIPTOR, PSID};
+
+/// Wraps GetNamedSecurityInfoW
+///
+/// `path` names a file (this always passes `SE_FILE_OBJECT` as the object
+/// type); `info` selects which of the owner/group/DACL/SACL are fetched.
+/// The returned descriptor is a single LocalAlloc'd blob with interior
+/// owner/group/DACL/SACL pointers, which maps directly onto
+/// `SecurityDescriptor::from_raw`.
+#[allow(non_snake_case)]
+pub fn GetNamedSecurityInfo(
+    path: &OsStr,
+    info: SecurityInformation,
+) -> io::Result<SecurityDescriptor> {
+    let wide: Vec<u16> = path.encode_wide().chain(Some(0)).collect();
+
+    let mut owner: PSID = ptr::null_mut();
+    let mut group: PSID = ptr::null_mut();
+    let mut dacl: PACL = ptr::null_mut();
+    let mut sacl: PACL = ptr::null_mut();
+    let mut sd: PSECURITY_DESCRIPTOR = ptr::null_mut();
+
+    let result = unsafe {
+        GetNamedSecurityInfoW(
+            wide.as_ptr() as *mut _,
+            SE_FILE_OBJECT,
+            info.bits(),
+            &mut owner,
+            &mut group,
+            &mut dacl,
+            &mut sacl,
+            &mut sd,
+        )
+    };
+
+    if result != ERROR_SUCCESS {
+        return Err(io::Error::from_raw_os_error(result as i32));
+    }
+
+    Ok(unsafe { SecurityDescriptor::from_raw(sd, owner, group, dacl, sacl) })
+}
@@ -0,0 +1,30 @@
+use std::ptr;
+use winapi::shared::minwindef::BOOL;
+use winapi::um::winnt::{PACL, PSECURITY_DESCRIPTOR};
+
+/// Wraps GetSecurityDescriptorDacl
+///
+/// Returns a null pointer if `sd` has no DACL present, mirroring what
+/// `SecurityDescriptor::from_raw` already expects for a missing DACL. The
+/// `lpbDaclDefaulted` out-param is discarded.
+#[allow(non_snake_case)]
+pub fn GetSecurityDescriptorDacl(sd: PSECURITY_DESCRIPTOR) -> PACL {
+    let mut present: BOOL = 0;
+    let mut dacl: PACL = ptr::null_mut();
+    let mut defaulted: BOOL = 0;
+
+    unsafe {
+        winapi::um::securitybaseapi::GetSecurityDescriptorDacl(
+            sd,
+            &mut present,
+            &mut dacl,
+            &mut defaulted,
+        )
+    };
+
+    if present == 0 {
+        ptr::null_mut()
+    } else {
+        dacl
+    }
+}
@@ -0,0 +1,20 @@
+use std::ptr;
+use winapi::shared::minwindef::BOOL;
+use winapi::um::winnt::{PSECURITY_DESCRIPTOR, PSID};
+
+/// Wraps GetSecurityDescriptorGroup
+///
+/// Returns a null pointer if `sd` has no group. The `lpbGroupDefaulted` out-param
+/// is discarded; callers of this crate don't currently need to know
+/// whether the group came from a default ACL vs. being set explicitly.
+#[allow(non_snake_case)]
+pub fn GetSecurityDescriptorGroup(sd: PSECURITY_DESCRIPTOR) -> PSID {
+    let mut group: PSID = ptr::null_mut();
+    let mut defaulted: BOOL = 0;
+
+    unsafe {
+        winapi::um::securitybaseapi::GetSecurityDescriptorGroup(sd, &mut group, &mut defaulted)
+    };
+
+    group
+}
@@ -0,0 +1,20 @@
+use std::ptr;
+use winapi::shared::minwindef::BOOL;
+use winapi::um::winnt::{PSECURITY_DESCRIPTOR, PSID};
+
+/// Wraps GetSecurityDescriptorOwner
+///
+/// Returns a null pointer if `sd` has no owner. The `lpbOwnerDefaulted` out-param
+/// is discarded; callers of this crate don't currently need to know
+/// whether the owner came from a default ACL vs. being set explicitly.
+#[allow(non_snake_case)]
+pub fn GetSecurityDescriptorOwner(sd: PSECURITY_DESCRIPTOR) -> PSID {
+    let mut owner: PSID = ptr::null_mut();
+    let mut defaulted: BOOL = 0;
+
+    unsafe {
+        winapi::um::securitybaseapi::GetSecurityDescriptorOwner(sd, &mut owner, &mut defaulted)
+    };
+
+    owner
+}
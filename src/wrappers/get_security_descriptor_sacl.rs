@@ -0,0 +1,30 @@
+use std::ptr;
+use winapi::shared::minwindef::BOOL;
+use winapi::um::winnt::{PACL, PSECURITY_DESCRIPTOR};
+
+/// Wraps GetSecurityDescriptorSacl
+///
+/// Returns a null pointer if `sd` has no SACL present, mirroring what
+/// `SecurityDescriptor::from_raw` already expects for a missing SACL. The
+/// `lpbSaclDefaulted` out-param is discarded.
+#[allow(non_snake_case)]
+pub fn GetSecurityDescriptorSacl(sd: PSECURITY_DESCRIPTOR) -> PACL {
+    let mut present: BOOL = 0;
+    let mut sacl: PACL = ptr::null_mut();
+    let mut defaulted: BOOL = 0;
+
+    unsafe {
+        winapi::um::securitybaseapi::GetSecurityDescriptorSacl(
+            sd,
+            &mut present,
+            &mut sacl,
+            &mut defaulted,
+        )
+    };
+
+    if present == 0 {
+        ptr::null_mut()
+    } else {
+        sacl
+    }
+}
@@ -0,0 +1,19 @@
+use crate::{wrappers, Sid};
+
+/// Wraps GetSidSubAuthority, returning all of a SID's sub-authorities at
+/// once
+///
+/// Relies on a SID's sub-authorities being stored contiguously starting
+/// at index 0, which `GetSidSubAuthority` guarantees.
+#[allow(non_snake_case)]
+pub fn GetSidSubAuthorities(sid: &Sid) -> &[u32] {
+    let count = wrappers::GetSidSubAuthorityCount(sid) as usize;
+
+    if count == 0 {
+        return &[];
+    }
+
+    let first = unsafe { winapi::um::securitybaseapi::GetSidSubAuthority(sid.as_ptr() as *mut _, 0) };
+
+    unsafe { std::slice::from_raw_parts(first, count) }
+}
@@ -0,0 +1,28 @@
+use crate::Acl;
+use std::io;
+use std::ptr::NonNull;
+use winapi::um::winbase::{LocalAlloc, LPTR};
+use winapi::um::winnt::{ACL, ACL_REVISION};
+
+/// Wraps InitializeAcl
+///
+/// Allocates a buffer of `len` bytes with `LocalAlloc(LPTR, ...)` (so the
+/// returned `Acl`'s `LocalFree`-based `Drop` stays valid) and initializes
+/// it as an empty ACL, ready to have ACEs added with e.g.
+/// `AddAccessAllowedAce`.
+#[allow(non_snake_case)]
+pub fn InitializeAcl(len: u32) -> io::Result<Acl> {
+    let buf = unsafe { LocalAlloc(LPTR, len as usize) };
+    let buf = NonNull::new(buf).ok_or_else(io::Error::last_os_error)?;
+
+    let result =
+        unsafe { winapi::um::securitybaseapi::InitializeAcl(buf.as_ptr() as *mut ACL, len, ACL_REVISION) };
+
+    if result == 0 {
+        let err = io::Error::last_os_error();
+        unsafe { winapi::um::winbase::LocalFree(buf.as_ptr()) };
+        return Err(err);
+    }
+
+    Ok(unsafe { Acl::owned_from_nonnull(buf.cast()) })
+}
@@ -0,0 +1,80 @@
+use crate::{Account, Sid};
+use std::ffi::{OsStr, OsString};
+use std::io;
+use std::os::windows::ffi::{OsStrExt, OsStringExt};
+use std::ptr;
+use winapi::shared::minwindef::DWORD;
+use winapi::shared::winerror::ERROR_INSUFFICIENT_BUFFER;
+use winapi::um::winbase::LookupAccountSidW;
+use winapi::um::winnt::SID_NAME_USE;
+
+/// Wraps LookupAccountSidW
+///
+/// `system` names the computer to search for the account; pass `None` to
+/// search the local system first, then known domains.
+///
+/// This uses the usual two-pass idiom: the first call is made with empty
+/// buffers, which fails with `ERROR_INSUFFICIENT_BUFFER` and fills in the
+/// required name and domain lengths (in `u16`s, including the nul
+/// terminator), then `Vec<u16>` buffers of those lengths are allocated and
+/// the call is repeated to fill them in.
+#[allow(non_snake_case)]
+pub fn LookupAccountSid(sid: &Sid, system: Option<&OsStr>) -> io::Result<Account> {
+    let system_wide: Option<Vec<u16>> = system.map(|s| s.encode_wide().chain(Some(0)).collect());
+    let system_ptr = system_wide
+        .as_ref()
+        .map(|s| s.as_ptr())
+        .unwrap_or(ptr::null());
+
+    let mut name_len: DWORD = 0;
+    let mut domain_len: DWORD = 0;
+    let mut sid_type: SID_NAME_USE = 0;
+
+    let result = unsafe {
+        LookupAccountSidW(
+            system_ptr,
+            sid.as_ptr() as *mut _,
+            ptr::null_mut(),
+            &mut name_len,
+            ptr::null_mut(),
+            &mut domain_len,
+            &mut sid_type,
+        )
+    };
+
+    if result == 0 {
+        let err = io::Error::last_os_error();
+        if err.raw_os_error() != Some(ERROR_INSUFFICIENT_BUFFER as i32) {
+            return Err(err);
+        }
+    }
+
+    let mut name_buf: Vec<u16> = vec![0; name_len as usize];
+    let mut domain_buf: Vec<u16> = vec![0; domain_len as usize];
+
+    let result = unsafe {
+        LookupAccountSidW(
+            system_ptr,
+            sid.as_ptr() as *mut _,
+            name_buf.as_mut_ptr(),
+            &mut name_len,
+            domain_buf.as_mut_ptr(),
+            &mut domain_len,
+            &mut sid_type,
+        )
+    };
+
+    if result == 0 {
+        return Err(io::Error::last_os_error());
+    }
+
+    // Truncate the trailing nul terminators written by LookupAccountSidW
+    name_buf.truncate(name_len as usize);
+    domain_buf.truncate(domain_len as usize);
+
+    Ok(Account::new(
+        OsString::from_wide(&name_buf),
+        OsString::from_wide(&domain_buf),
+        sid_type,
+    ))
+}
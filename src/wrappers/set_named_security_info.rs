@@ -0,0 +1,58 @@
+use crate::{SecurityDescriptor, SecurityInformation};
+use std::ffi::OsStr;
+use std::io;
+use std::os::windows::ffi::OsStrExt;
+use std::ptr;
+use winapi::shared::winerror::ERROR_SUCCESS;
+use winapi::um::accctrl::SE_FILE_OBJECT;
+use winapi::um::aclapi::SetNamedSecurityInfoW;
+
+/// Wraps SetNamedSecurityInfoW
+///
+/// `path` names a file (this always passes `SE_FILE_OBJECT` as the object
+/// type); `info` selects which of `sd`'s owner/group/DACL/SACL are
+/// written. Components `sd` doesn't have are passed as null, which the
+/// API ignores for any component not also selected by `info`.
+#[allow(non_snake_case)]
+pub fn SetNamedSecurityInfo(
+    path: &OsStr,
+    info: SecurityInformation,
+    sd: &SecurityDescriptor,
+) -> io::Result<()> {
+    let mut wide: Vec<u16> = path.encode_wide().chain(Some(0)).collect();
+
+    let owner = sd
+        .owner()
+        .map(|s| s.as_ptr() as *mut _)
+        .unwrap_or(ptr::null_mut());
+    let group = sd
+        .group()
+        .map(|s| s.as_ptr() as *mut _)
+        .unwrap_or(ptr::null_mut());
+    let dacl = sd
+        .dacl()
+        .map(|a| a.as_ptr() as *mut _)
+        .unwrap_or(ptr::null_mut());
+    let sacl = sd
+        .sacl()
+        .map(|a| a.as_ptr() as *mut _)
+        .unwrap_or(ptr::null_mut());
+
+    let result = unsafe {
+        SetNamedSecurityInfoW(
+            wide.as_mut_ptr(),
+            SE_FILE_OBJECT,
+            info.bits(),
+            owner,
+            group,
+            dacl,
+            sacl,
+        )
+    };
+
+    if result != ERROR_SUCCESS {
+        return Err(io::Error::from_raw_os_error(result as i32));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use crate::{SecurityDescriptor, SecurityInformation};
+use std::io;
+use std::ptr;
+use winapi::shared::winerror::ERROR_SUCCESS;
+use winapi::um::accctrl::SE_FILE_OBJECT;
+use winapi::um::aclapi::SetSecurityInfo as SetSecurityInfo_sys;
+use winapi::um::winnt::HANDLE;
+
+/// Wraps SetSecurityInfo
+///
+/// `handle` must be open with the access rights `info` requires (e.g.
+/// `WRITE_DAC` to set the DACL). This always passes `SE_FILE_OBJECT` as
+/// the object type; `info` selects which of `sd`'s owner/group/DACL/SACL
+/// are written, with components `sd` doesn't have passed as null.
+#[allow(non_snake_case)]
+pub fn SetSecurityInfo(
+    handle: HANDLE,
+    info: SecurityInformation,
+    sd: &SecurityDescriptor,
+) -> io::Result<()> {
+    let owner = sd
+        .owner()
+        .map(|s| s.as_ptr() as *mut _)
+        .unwrap_or(ptr::null_mut());
+    let group = sd
+        .group()
+        .map(|s| s.as_ptr() as *mut _)
+        .unwrap_or(ptr::null_mut());
+    let dacl = sd
+        .dacl()
+        .map(|a| a.as_ptr() as *mut _)
+        .unwrap_or(ptr::null_mut());
+    let sacl = sd
+        .sacl()
+        .map(|a| a.as_ptr() as *mut _)
+        .unwrap_or(ptr::null_mut());
+
+    let result = unsafe {
+        SetSecurityInfo_sys(
+            handle,
+            SE_FILE_OBJECT,
+            info.bits(),
+            owner,
+            group,
+            dacl,
+            sacl,
+        )
+    };
+
+    if result != ERROR_SUCCESS {
+        return Err(io::Error::from_raw_os_error(result as i32));
+    }
+
+    Ok(())
+}